@@ -1,16 +1,23 @@
 use anyhow::{Context, Result};
 use glob::glob;
-use log::{debug, error};
+use tracing::{debug, error, info, warn};
 use prometheus_client::collector::Collector;
 use prometheus_client::encoding::{DescriptorEncoder, EncodeLabelSet, EncodeMetric};
+use prometheus_client::metrics::MetricType;
 use prometheus_client::metrics::counter::ConstCounter;
 use prometheus_client::metrics::gauge::ConstGauge;
+use prometheus_client::registry::Unit;
 use regex::Regex;
 use std::collections::BTreeMap;
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
-use std::time::SystemTime;
-use tokio::fs::{File, metadata};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::metrics::ExporterMetrics;
+use std::{ffi::OsStr, fs};
+use arc_swap::ArcSwap;
+use tokio::fs::File;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use uuid::Uuid;
 
@@ -29,27 +36,52 @@ struct ProgressRow {
     gen_max: u64,
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
-struct UuidLabel {
-    uuid: String,
+/// A flat set of label key/value pairs. Used instead of a fixed struct so the
+/// dynamic `uuid`/`extent_size` labels can be concatenated with the operator's
+/// configured static labels and handed to `encode_family` as a single
+/// [`EncodeLabelSet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LabelSet {
+    labels: Vec<(String, String)>,
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
-struct UuidExtentLabel {
-    uuid: String,
-    extent_size: String,
+impl EncodeLabelSet for LabelSet {
+    fn encode(
+        &self,
+        mut encoder: prometheus_client::encoding::LabelSetEncoder,
+    ) -> Result<(), std::fmt::Error> {
+        use prometheus_client::encoding::EncodeLabel;
+        for label in &self.labels {
+            (label.0.as_str(), label.1.as_str()).encode(encoder.encode_label())?;
+        }
+        Ok(())
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct FsMetrics {
     stats: BTreeMap<String, f64>,
+    rates: BTreeMap<String, f64>,
     progress: Vec<ProgressRow>,
+    /// Resource usage of the bees daemon serving this filesystem, collected
+    /// alongside the status file during the background refresh. `None` when no
+    /// matching process was found.
+    process: Option<ProcessMetrics>,
     // Adding timestamps to metrics is currently not supported in the Rust client
     // See https://github.com/prometheus/client_rust/issues/126
     #[allow(unused)]
     timestamp: u64,
 }
 
+/// Resource usage of the bees daemon serving a given filesystem, scraped from
+/// `/proc`. Absent when no matching process is running or the pid vanished
+/// mid-scrape.
+#[derive(Debug, Clone)]
+struct ProcessMetrics {
+    cpu_seconds: f64,
+    resident_bytes: u64,
+}
+
 #[derive(Debug)]
 enum ParserState {
     None,
@@ -58,54 +90,240 @@ enum ParserState {
     Progress,
 }
 
+/// A parsed snapshot of every bees filesystem keyed by UUID.
+type Snapshot = BTreeMap<Uuid, FsMetrics>;
+
+/// Reads and parses bees status files. Holds no per-scrape state, so it can be
+/// shared cheaply between the background refresh task and the collector.
 #[derive(Debug)]
-pub struct BeesCollector {
-    stats_dir: PathBuf,
+struct Scraper {
+    /// Directories to scan for `*.status` files, expanded from the work-dir
+    /// glob pattern.
+    roots: Vec<PathBuf>,
     pattern: Regex,
+    rate_pattern: Regex,
+    metrics: ExporterMetrics,
+}
+
+#[derive(Debug)]
+pub struct BeesCollector {
+    scraper: Arc<Scraper>,
+    snapshot: Arc<ArcSwap<Snapshot>>,
+    /// Static labels merged into every exported metric.
+    labels: Vec<(String, String)>,
 }
 
 impl BeesCollector {
-    pub async fn new(stats_dir: PathBuf) -> Result<Self> {
-        // Verify directory exists and is accessible
-        metadata(&stats_dir)
-            .await
-            .with_context(|| format!("Cannot access stats directory: {:?}", stats_dir))?;
+    /// Create a collector for the given work-dir pattern. The pattern may be a
+    /// plain directory or a glob (e.g. `/run/bees/*/` or `/mnt/*/.beeshome`)
+    /// matching several bees instances spread across mount points.
+    pub async fn new(
+        work_dir: PathBuf,
+        labels: Vec<(String, String)>,
+        metrics: ExporterMetrics,
+    ) -> Result<Self> {
+        let roots = expand_roots(&work_dir)?;
+        if roots.is_empty() {
+            warn!("Work-dir pattern {:?} matched no directories", work_dir);
+        } else {
+            for root in &roots {
+                info!("Scanning bees work dir: {}", root.display());
+            }
+        }
 
         let pattern =
             Regex::new(r"(?-u:(\w+)=(\d+))").context("Failed to compile regex pattern")?;
 
-        Ok(BeesCollector { stats_dir, pattern })
+        // RATES values are per-second figures and carry a decimal point.
+        let rate_pattern = Regex::new(r"(?-u:(\w+)=(\d+(?:\.\d+)?))")
+            .context("Failed to compile rate regex pattern")?;
+
+        let scraper = Arc::new(Scraper {
+            roots,
+            pattern,
+            rate_pattern,
+            metrics,
+        });
+
+        // Seed the snapshot once so the first scrape is never empty.
+        let initial = scraper.collect_all_data().await?;
+        let snapshot = Arc::new(ArcSwap::from_pointee(initial));
+
+        Ok(BeesCollector {
+            scraper,
+            snapshot,
+            labels,
+        })
+    }
+
+    /// Build a label set carrying the filesystem `uuid` plus the configured
+    /// static labels.
+    fn uuid_labels(&self, uuid: &Uuid) -> LabelSet {
+        let mut labels = Vec::with_capacity(1 + self.labels.len());
+        labels.push(("uuid".to_string(), uuid.as_hyphenated().to_string()));
+        labels.extend(self.labels.iter().cloned());
+        LabelSet { labels }
+    }
+
+    /// Build a label set carrying the filesystem `uuid`, the `extent_size`, and
+    /// the configured static labels.
+    fn extent_labels(&self, uuid: &Uuid, extent_size: &str) -> LabelSet {
+        let mut labels = Vec::with_capacity(2 + self.labels.len());
+        labels.push(("uuid".to_string(), uuid.as_hyphenated().to_string()));
+        labels.push(("extent_size".to_string(), extent_size.to_string()));
+        labels.extend(self.labels.iter().cloned());
+        LabelSet { labels }
+    }
+
+    /// Spawn a background task that periodically re-reads the status files and
+    /// publishes a fresh snapshot. A failing cycle is logged and leaves the last
+    /// good snapshot in place rather than clearing it.
+    pub fn spawn_refresh(&self, interval: Duration) {
+        let scraper = Arc::clone(&self.scraper);
+        let snapshot = Arc::clone(&self.snapshot);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            // Skip the immediate first tick; new() already seeded the snapshot.
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                match scraper.collect_all_data().await {
+                    Ok(data) => snapshot.store(Arc::new(data)),
+                    Err(e) => error!("Failed to refresh bees metrics snapshot: {}", e),
+                }
+            }
+        });
+    }
+
+    /// Watch the stats directory with the platform's native backend
+    /// (inotify/kqueue) and incrementally update the snapshot as bees rewrites
+    /// its status files. Changes are coalesced over a `debounce` window so the
+    /// rapid rewrites bees performs collapse into a single re-parse.
+    ///
+    /// Only the changed files are re-read; unaffected filesystems keep their
+    /// last parsed values. Use [`spawn_refresh`](Self::spawn_refresh) instead
+    /// for backends that do not support file watching.
+    pub fn spawn_watcher(&self, debounce: Duration) -> Result<()> {
+        use notify_debouncer_mini::{DebounceEventResult, new_debouncer, notify::RecursiveMode};
+
+        let scraper = Arc::clone(&self.scraper);
+        let snapshot = Arc::clone(&self.snapshot);
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Vec<PathBuf>>();
+
+        let mut debouncer = new_debouncer(debounce, move |res: DebounceEventResult| match res {
+            Ok(events) => {
+                let paths = events.into_iter().map(|event| event.path).collect();
+                // The receiver lives as long as the spawned task; ignore send
+                // errors that only occur once it has shut down.
+                let _ = tx.send(paths);
+            }
+            Err(e) => error!("File watcher error: {}", e),
+        })
+        .context("Failed to create file watcher")?;
+
+        for root in &scraper.roots {
+            debouncer
+                .watcher()
+                .watch(root, RecursiveMode::NonRecursive)
+                .with_context(|| format!("Failed to watch directory: {:?}", root))?;
+        }
+
+        tokio::spawn(async move {
+            // Hold the debouncer for the lifetime of the task; dropping it stops
+            // the watch.
+            let _debouncer = debouncer;
+            while let Some(paths) = rx.recv().await {
+                let mut map: Snapshot = snapshot.load().as_ref().clone();
+                for path in paths {
+                    scraper.apply_path_change(&path, &mut map).await;
+                }
+                snapshot.store(Arc::new(map));
+            }
+        });
+
+        Ok(())
     }
+}
 
+impl Scraper {
     /// Collect all data from bees status files
     async fn collect_all_data(&self) -> Result<BTreeMap<Uuid, FsMetrics>> {
-        let status_file_pattern = format!("{}/*.status", self.stats_dir.display());
+        let start = Instant::now();
         let mut values: BTreeMap<Uuid, FsMetrics> = BTreeMap::new();
 
-        for entry in glob(&status_file_pattern)
-            .context("Failed to create glob pattern")?
-            .filter_map(Result::ok)
-        {
-            if let Some(uuid) = entry
-                .file_stem()
-                .and_then(|s| Uuid::try_parse_ascii(s.as_bytes()).ok())
+        for root in &self.roots {
+            let status_file_pattern = format!("{}/*.status", root.display());
+
+            for entry in glob(&status_file_pattern)
+                .context("Failed to create glob pattern")?
+                .filter_map(Result::ok)
             {
-                match self.collect_stats_from_file(&entry).await {
-                    Ok(stats) => {
-                        values.insert(uuid, stats);
-                    }
-                    Err(e) => {
-                        error!("Failed to collect stats from {}: {}", entry.display(), e);
+                if let Some(uuid) = entry
+                    .file_stem()
+                    .and_then(|s| Uuid::try_parse_ascii(s.as_bytes()).ok())
+                {
+                    match self.collect_stats_from_file(&entry).await {
+                        Ok(mut stats) => {
+                            // Discover the serving daemon and read its /proc
+                            // figures here, in the background refresh, so the
+                            // scrape path stays free of blocking I/O.
+                            stats.process = find_bees_pid(&uuid).and_then(read_process_metrics);
+                            values.insert(uuid, stats);
+                        }
+                        Err(e) => {
+                            self.metrics.parse_failures.inc();
+                            error!("Failed to collect stats from {}: {}", entry.display(), e);
+                        }
                     }
+                } else {
+                    self.metrics.parse_failures.inc();
+                    error!("Failed to parse UUID from filename: {}", entry.display());
                 }
-            } else {
-                error!("Failed to parse UUID from filename: {}", entry.display());
             }
         }
 
+        self.metrics
+            .collect_duration
+            .observe(start.elapsed().as_secs_f64());
+        self.metrics.filesystems.set(values.len() as i64);
+
         Ok(values)
     }
 
+    /// Apply a single watched path change to `map`: re-parse the file if it is a
+    /// `<uuid>.status` file that still exists, or drop the corresponding entry
+    /// if it was removed. Non-status paths are ignored.
+    async fn apply_path_change(&self, path: &Path, map: &mut Snapshot) {
+        if path.extension().and_then(|ext| ext.to_str()) != Some("status") {
+            return;
+        }
+
+        let Some(uuid) = path
+            .file_stem()
+            .and_then(|stem| Uuid::try_parse_ascii(stem.as_bytes()).ok())
+        else {
+            return;
+        };
+
+        if path.exists() {
+            match self.collect_stats_from_file(path).await {
+                Ok(mut stats) => {
+                    stats.process = find_bees_pid(&uuid).and_then(read_process_metrics);
+                    map.insert(uuid, stats);
+                }
+                Err(e) => {
+                    self.metrics.parse_failures.inc();
+                    error!("Failed to collect stats from {}: {}", path.display(), e);
+                }
+            }
+        } else {
+            map.remove(&uuid);
+        }
+        self.metrics.filesystems.set(map.len() as i64);
+    }
+
     async fn collect_stats_from_file(&self, stats_file: &Path) -> Result<FsMetrics> {
         let file = File::open(stats_file)
             .await
@@ -138,6 +356,7 @@ impl BeesCollector {
         }
 
         let mut stats: BTreeMap<String, f64> = BTreeMap::new();
+        let mut rates: BTreeMap<String, f64> = BTreeMap::new();
         let mut progress: Vec<ProgressRow> = Vec::new();
         let mut parser_state = ParserState::None;
         let mut line_iter = file_lines.iter();
@@ -158,7 +377,16 @@ impl BeesCollector {
             }
 
             match parser_state {
-                ParserState::Rates | ParserState::None => continue,
+                ParserState::None => continue,
+                ParserState::Rates => match self.parse_rates_line(line) {
+                    Ok(parsed_rates) => {
+                        rates.extend(parsed_rates);
+                    }
+                    Err(e) => {
+                        error!("Failed to parse RATES line '{}': {}", line, e);
+                        // Continue processing other lines despite this error
+                    }
+                },
                 ParserState::Total => {
                     match self.parse_total_line(line) {
                         Ok(parsed_metrics) => {
@@ -185,7 +413,9 @@ impl BeesCollector {
 
         Ok(FsMetrics {
             stats,
+            rates,
             progress,
+            process: None,
             timestamp,
         })
     }
@@ -223,6 +453,36 @@ impl BeesCollector {
         Ok(ret)
     }
 
+    fn parse_rates_line(&self, line: &str) -> Result<Vec<(String, f64)>> {
+        let mut ret = Vec::new();
+        for caps in line
+            .split_ascii_whitespace()
+            .filter_map(|word| self.rate_pattern.captures(word))
+        {
+            let metric_name = caps
+                .get(1)
+                .context("Failed to capture rate name from regex")?
+                .as_str()
+                .to_string();
+            let value: f64 = caps
+                .get(2)
+                .context("Failed to capture rate value from regex")?
+                .as_str()
+                .parse()
+                .with_context(|| {
+                    format!(
+                        "Failed to parse rate value: {}",
+                        caps.get(0).unwrap().as_str()
+                    )
+                })?;
+            ret.push((metric_name, value));
+        }
+        if ret.is_empty() {
+            return Err(anyhow::anyhow!("No rates parsed from RATES line: {}", line));
+        }
+        Ok(ret)
+    }
+
     fn parse_progress_lines(
         &self,
         lines: &mut std::slice::Iter<String>,
@@ -336,38 +596,218 @@ impl BeesCollector {
     }
 }
 
+/// Expand a work-dir pattern into the set of directories to scan. A plain
+/// directory expands to itself; a glob (e.g. `/mnt/*/.beeshome`) expands to
+/// every matching directory. Slashes are normalized with `path-slash` so the
+/// pattern behaves consistently across platforms.
+fn expand_roots(pattern: &Path) -> Result<Vec<PathBuf>> {
+    use path_slash::PathExt;
+
+    let pattern_str = pattern.to_slash_lossy();
+    let mut roots: Vec<PathBuf> = glob(&pattern_str)
+        .context("Failed to expand work-dir glob pattern")?
+        .filter_map(Result::ok)
+        .filter(|path| path.is_dir())
+        .collect();
+
+    // A literal, non-glob directory that glob did not return (e.g. a trailing
+    // slash mismatch) still counts as a root.
+    if roots.is_empty() && pattern.is_dir() {
+        roots.push(pattern.to_path_buf());
+    }
+
+    roots.sort();
+    roots.dedup();
+    Ok(roots)
+}
+
+/// Classify a bees TOTAL stat into its base metric name, metric type,
+/// OpenMetrics unit, and a scale factor applied to the raw value.
+///
+/// Most bees TOTAL fields are monotonic counters, but a handful report an
+/// instantaneous value and are better modelled as gauges. Byte accumulators
+/// carry [`Unit::Bytes`] and elapsed-time fields [`Unit::Seconds`]. Unknown
+/// names fall back to an unitless counter so newly added bees fields still
+/// surface.
+///
+/// The returned base name has the recognised unit token stripped: prometheus-
+/// client appends the unit suffix itself, so leaving e.g. a trailing `_bytes`
+/// in place would double it to `..._bytes_bytes`. The scale factor converts
+/// millisecond fields to the seconds their unit advertises.
+///
+/// Classification is grounded in the bees `BeesStats` TOTAL field set rather
+/// than inferred from arbitrary suffixes: a blanket `_s`/`_ms` heuristic could
+/// map two distinct fields onto the same `bees_<base>_seconds` descriptor and
+/// produce malformed exposition. Fields outside these tables surface as plain
+/// unitless counters under their raw name, which is unique per field.
+fn classify_stat(name: &str) -> (String, MetricType, Option<Unit>, f64) {
+    // Instantaneous values bees reports under TOTAL despite the section name.
+    const GAUGES: &[&str] = &["thread_count"];
+    // Byte accumulators.
+    const BYTES: &[&str] = &[
+        "block_bytes",
+        "dedup_bytes",
+        "dedup_prealloc_bytes",
+        "scan_skip_bytes",
+        "tmp_bytes",
+    ];
+    // Elapsed-time counters; bees reports these in milliseconds.
+    const MILLISECONDS: &[&str] = &[
+        "addr_ms",
+        "block_ms",
+        "crawl_ms",
+        "dedup_ms",
+        "pairbackward_ms",
+        "pairforward_ms",
+        "readahead_ms",
+        "resolve_ms",
+        "scan_skip_ms",
+        "scanf_extent_ms",
+        "scanf_total_ms",
+        "tmp_aftersync_ms",
+        "tmp_copy_ms",
+        "tmp_create_ms",
+        "tmp_resize_ms",
+    ];
+
+    if GAUGES.contains(&name) {
+        return (name.to_string(), MetricType::Gauge, None, 1.0);
+    }
+    if BYTES.contains(&name) {
+        let base = name.strip_suffix("_bytes").unwrap_or(name);
+        return (base.to_string(), MetricType::Counter, Some(Unit::Bytes), 1.0);
+    }
+    if MILLISECONDS.contains(&name) {
+        let base = name.strip_suffix("_ms").unwrap_or(name);
+        // Scale milliseconds to the advertised seconds unit.
+        return (
+            base.to_string(),
+            MetricType::Counter,
+            Some(Unit::Seconds),
+            0.001,
+        );
+    }
+
+    (name.to_string(), MetricType::Counter, None, 1.0)
+}
+
+/// Find the pid of the running bees daemon that serves the given filesystem
+/// `uuid` by scanning `/proc/*/cmdline`. bees is invoked with the filesystem
+/// UUID (or a path containing it) on its command line, so we match the uuid
+/// string against any argument of a `bees` process.
+fn find_bees_pid(uuid: &Uuid) -> Option<u32> {
+    let needle = uuid.as_hyphenated().to_string();
+    let self_pid = std::process::id();
+
+    for entry in fs::read_dir("/proc").ok()?.filter_map(Result::ok) {
+        let pid: u32 = match entry.file_name().to_str().and_then(|s| s.parse().ok()) {
+            Some(pid) => pid,
+            None => continue,
+        };
+
+        // The exporter carries the filesystem UUID in its own --bees-work-dir
+        // argument, so skip ourselves to avoid reporting exporter usage as bees.
+        if pid == self_pid {
+            continue;
+        }
+
+        // cmdline is a NUL-separated argument vector.
+        let cmdline = match fs::read(entry.path().join("cmdline")) {
+            Ok(bytes) => bytes,
+            // The pid may have exited between readdir and open.
+            Err(_) => continue,
+        };
+
+        let mut args = cmdline.split(|&b| b == 0).filter(|a| !a.is_empty());
+        // Match the daemon exactly; a `bees*` prefix would also catch
+        // `bees-prometheus-exporter`.
+        let is_bees = args
+            .clone()
+            .next()
+            .map(|arg0| {
+                Path::new(OsStr::from_bytes(arg0))
+                    .file_name()
+                    .is_some_and(|name| matches!(name.as_bytes(), b"bees" | b"beesd"))
+            })
+            .unwrap_or(false);
+
+        if is_bees
+            && args.any(|arg| String::from_utf8_lossy(arg).contains(needle.as_str()))
+        {
+            return Some(pid);
+        }
+    }
+
+    None
+}
+
+/// Read CPU and resident-memory figures for `pid` from `/proc`. Returns `None`
+/// if the process exited between discovery and read, so the caller can skip
+/// that filesystem for the scrape instead of failing the whole encode.
+fn read_process_metrics(pid: u32) -> Option<ProcessMetrics> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // The comm field (2) is wrapped in parentheses and may itself contain
+    // spaces, so split after the closing paren to index the remaining fields.
+    let after_comm = &stat[stat.rfind(')')? + 1..];
+    let fields: Vec<&str> = after_comm.split_ascii_whitespace().collect();
+    // Fields 14 (utime) and 15 (stime) are at offsets 11 and 12 after comm.
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+
+    let clk_tck = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    let cpu_seconds = if clk_tck > 0 {
+        (utime + stime) as f64 / clk_tck as f64
+    } else {
+        0.0
+    };
+
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let resident_bytes = status.lines().find_map(|line| {
+        let kb: u64 = line.strip_prefix("VmRSS:")?.split_ascii_whitespace().next()?.parse().ok()?;
+        Some(kb * 1024)
+    })?;
+
+    Some(ProcessMetrics {
+        cpu_seconds,
+        resident_bytes,
+    })
+}
+
 impl Collector for BeesCollector {
     fn encode(&self, mut encoder: DescriptorEncoder) -> Result<(), std::fmt::Error> {
-        // Collect all data from bees status files
-        let values = match tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current().block_on(self.collect_all_data())
-        }) {
-            Ok(data) => data,
-            Err(e) => {
-                error!("Failed to collect metrics: {}", e);
-                return Ok(()); // Don't fail the encoding, just skip metrics
-            }
-        };
+        // The scrape counter is incremented by the `/metrics` HTTP handler, not
+        // here: this encode path is also driven by the OTLP export task, which
+        // must not be counted as a Prometheus scrape.
+
+        // Load the latest background-refreshed snapshot. No blocking and no
+        // per-scrape file I/O: concurrent scrapes share one parse.
+        let snapshot = self.snapshot.load();
+        let values = snapshot.as_ref();
 
         // Group metrics by type to encode descriptors properly
-        let mut stats_counters: BTreeMap<String, Vec<(UuidLabel, f64)>> = BTreeMap::new();
-        let mut datasz_gauges: Vec<(UuidExtentLabel, i64)> = Vec::new();
-        let mut point_gauges: Vec<(UuidExtentLabel, i64)> = Vec::new();
-        let mut point_idle_gauges: Vec<(UuidExtentLabel, i64)> = Vec::new();
-        let mut gen_min_gauges: Vec<(UuidExtentLabel, i64)> = Vec::new();
-        let mut gen_max_gauges: Vec<(UuidExtentLabel, i64)> = Vec::new();
+        let mut stats_counters: BTreeMap<String, Vec<(LabelSet, f64)>> = BTreeMap::new();
+        let mut rate_gauges: BTreeMap<String, Vec<(LabelSet, f64)>> = BTreeMap::new();
+        let mut datasz_gauges: Vec<(LabelSet, i64)> = Vec::new();
+        let mut point_gauges: Vec<(LabelSet, i64)> = Vec::new();
+        let mut point_idle_gauges: Vec<(LabelSet, i64)> = Vec::new();
+        let mut gen_min_gauges: Vec<(LabelSet, i64)> = Vec::new();
+        let mut gen_max_gauges: Vec<(LabelSet, i64)> = Vec::new();
+
+        // Per-daemon resource usage, collected with the snapshot in the
+        // background refresh. A filesystem whose bees process was not found
+        // simply has no `process` entry and is skipped here.
+        let mut cpu_seconds: Vec<(LabelSet, f64)> = Vec::new();
+        let mut resident_bytes: Vec<(LabelSet, u64)> = Vec::new();
 
         // Process collected data and group by metric type
         for (uuid, fs_metrics) in values {
             // Group stats counters by metric name
-            for (metric_name, value) in fs_metrics.stats {
-                let label = UuidLabel {
-                    uuid: uuid.as_hyphenated().to_string(),
-                };
+            for (metric_name, value) in &fs_metrics.stats {
+                let label = self.uuid_labels(uuid);
                 stats_counters
                     .entry(metric_name.clone())
                     .or_default()
-                    .push((label, value));
+                    .push((label, *value));
 
                 debug!(
                     "Adding metric {} with value {} for uuid {}",
@@ -375,12 +815,18 @@ impl Collector for BeesCollector {
                 );
             }
 
+            // Group per-second rate gauges by metric name
+            for (metric_name, value) in &fs_metrics.rates {
+                let label = self.uuid_labels(uuid);
+                rate_gauges
+                    .entry(metric_name.clone())
+                    .or_default()
+                    .push((label, *value));
+            }
+
             // Group progress metrics
-            for progress_row in fs_metrics.progress {
-                let label = UuidExtentLabel {
-                    uuid: uuid.as_hyphenated().to_string(),
-                    extent_size: progress_row.extsz.clone(),
-                };
+            for progress_row in &fs_metrics.progress {
+                let label = self.extent_labels(uuid, &progress_row.extsz);
 
                 datasz_gauges.push((label.clone(), progress_row.datasz as i64));
 
@@ -399,33 +845,94 @@ impl Collector for BeesCollector {
                 gen_min_gauges.push((label.clone(), progress_row.gen_min as i64));
                 gen_max_gauges.push((label, progress_row.gen_max as i64));
             }
+
+            // Surface the daemon resource usage captured with this snapshot.
+            if let Some(proc_metrics) = &fs_metrics.process {
+                let label = self.uuid_labels(uuid);
+                cpu_seconds.push((label.clone(), proc_metrics.cpu_seconds));
+                resident_bytes.push((label, proc_metrics.resident_bytes));
+            }
+        }
+
+        if !cpu_seconds.is_empty() {
+            let mut metric_encoder = encoder.encode_descriptor(
+                "bees_process_cpu_seconds",
+                "Total CPU time consumed by the bees daemon",
+                None,
+                prometheus_client::metrics::MetricType::Counter,
+            )?;
+            for (label, value) in cpu_seconds {
+                let counter = ConstCounter::new(value);
+                let sample_encoder = metric_encoder.encode_family(&label)?;
+                counter.encode(sample_encoder)?;
+            }
+        }
+
+        if !resident_bytes.is_empty() {
+            let mut metric_encoder = encoder.encode_descriptor(
+                "bees_process_resident_memory_bytes",
+                "Resident memory size of the bees daemon in bytes",
+                None,
+                prometheus_client::metrics::MetricType::Gauge,
+            )?;
+            for (label, value) in resident_bytes {
+                let gauge = ConstGauge::new(value as i64);
+                let sample_encoder = metric_encoder.encode_family(&label)?;
+                gauge.encode(sample_encoder)?;
+            }
         }
 
-        // Encode stats counters
+        // Encode stats metrics with their classified type and unit.
         for (metric_name, label_values) in stats_counters {
-            let metric_registry_name = format!("bees_{}", metric_name.to_lowercase());
+            let (base_name, metric_type, unit, scale) = classify_stat(&metric_name);
+            let metric_registry_name = format!("bees_{}", base_name.to_lowercase());
             let description = format!("Bees metric {}", metric_name);
 
             let mut metric_encoder = encoder.encode_descriptor(
                 &metric_registry_name,
                 &description,
-                None,
-                prometheus_client::metrics::MetricType::Counter,
+                unit.as_ref(),
+                metric_type,
             )?;
 
             for (label, value) in label_values {
-                let counter = ConstCounter::new(value);
+                let value = value * scale;
                 let sample_encoder = metric_encoder.encode_family(&label)?;
-                counter.encode(sample_encoder)?;
+                match metric_type {
+                    MetricType::Gauge => ConstGauge::new(value).encode(sample_encoder)?,
+                    _ => ConstCounter::new(value).encode(sample_encoder)?,
+                }
+            }
+        }
+
+        // Encode per-second rate gauges
+        let per_second = Unit::Other("per_second".to_string());
+        for (metric_name, label_values) in rate_gauges {
+            let metric_registry_name = format!("bees_rate_{}", metric_name.to_lowercase());
+            let description = format!("Bees per-second rate {}", metric_name);
+
+            let mut metric_encoder = encoder.encode_descriptor(
+                &metric_registry_name,
+                &description,
+                Some(&per_second),
+                prometheus_client::metrics::MetricType::Gauge,
+            )?;
+
+            for (label, value) in label_values {
+                let gauge = ConstGauge::new(value);
+                let sample_encoder = metric_encoder.encode_family(&label)?;
+                gauge.encode(sample_encoder)?;
             }
         }
 
         // Encode progress summary gauges
         if !datasz_gauges.is_empty() {
+            // prometheus-client appends the `_bytes` unit suffix, so the base
+            // name omits it; the exposed series stays `..._datasz_bytes`.
             let mut metric_encoder = encoder.encode_descriptor(
-                "bees_progress_summary_datasz_bytes",
+                "bees_progress_summary_datasz",
                 "Bees progress summary datasz in bytes",
-                None,
+                Some(&Unit::Bytes),
                 prometheus_client::metrics::MetricType::Gauge,
             )?;
             for (label, value) in datasz_gauges {
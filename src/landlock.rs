@@ -2,9 +2,10 @@ use landlock::{
     ABI, Access, AccessFs, AccessNet, CompatLevel, Compatible, NetPort, RestrictionStatus, Ruleset,
     RulesetAttr, RulesetCreatedAttr, RulesetError, Scope, path_beneath_rules,
 };
-use log::{info, warn};
+use std::path::PathBuf;
+use tracing::{info, warn};
 
-use crate::config::Args;
+use crate::Args;
 
 pub fn init_landlock(args: &Args) -> Result<(), RulesetError> {
     let handle_success = |name: &str| {
@@ -31,26 +32,42 @@ pub fn init_landlock(args: &Args) -> Result<(), RulesetError> {
         }
     };
 
+    // The exporter reads its work dir plus `/proc` (per-daemon CPU/RSS). Only
+    // grant paths that actually exist so a glob work-dir pattern that resolves
+    // to nothing does not abort the ruleset.
+    let readable: Vec<PathBuf> = [PathBuf::from("/proc"), args.bees_work_dir.clone()]
+        .into_iter()
+        .filter(|path| path.exists())
+        .collect();
+
     handle_result(
         Ruleset::default()
             .set_compatibility(CompatLevel::BestEffort)
             .handle_access(AccessFs::from_all(ABI::V1))?
             .create()?
-            .add_rules(path_beneath_rules(
-                [&args.bees_work_dir],
-                AccessFs::from_read(ABI::V1),
-            ))?
+            .add_rules(path_beneath_rules(&readable, AccessFs::from_read(ABI::V1)))?
             .restrict_self(),
         "filesystem",
     );
 
     handle_result(
-        Ruleset::default()
-            .set_compatibility(CompatLevel::BestEffort)
-            .handle_access(AccessNet::from_all(ABI::V4))?
-            .create()?
-            .add_rule(NetPort::new(args.port, AccessNet::BindTcp))?
-            .restrict_self(),
+        {
+            // Restrict only binding; outbound connections (e.g. the OTLP push)
+            // stay unrestricted.
+            let ruleset = Ruleset::default()
+                .set_compatibility(CompatLevel::BestEffort)
+                .handle_access(AccessNet::BindTcp)?
+                .create()?
+                .add_rule(NetPort::new(args.port, AccessNet::BindTcp))?;
+            // tokio-console binds its own TCP port (6669 by default); permit it
+            // only when the operator opted in.
+            let ruleset = if args.tokio_console {
+                ruleset.add_rule(NetPort::new(6669, AccessNet::BindTcp))?
+            } else {
+                ruleset
+            };
+            ruleset.restrict_self()
+        },
         "network",
     );
 
@@ -1,22 +1,29 @@
-use anyhow::Result;
-use log::LevelFilter;
-use log4rs::{
-    Config,
-    append::console::ConsoleAppender,
-    config::{Appender, Root},
-};
-use std::str::FromStr;
+use anyhow::{Context, Result};
+use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, util::SubscriberInitExt};
 
-pub fn init_logger(level: &str) -> Result<()> {
-    let stdout = ConsoleAppender::builder().build();
-    let config = Config::builder()
-        .appender(Appender::builder().build("stdout", Box::new(stdout)))
-        .build(
-            Root::builder()
-                .appender("stdout")
-                .build(LevelFilter::from_str(level)?),
-        )?;
+/// Initialize the global `tracing` subscriber.
+///
+/// The `--log-level` argument seeds an [`EnvFilter`], which is still overridable
+/// through the usual `RUST_LOG` environment variable. When `tokio_console` is
+/// set, a `console-subscriber` layer is installed so operators can attach
+/// `tokio-console` to inspect the runtime's task tree.
+pub fn init_logger(level: &str, tokio_console: bool) -> Result<()> {
+    let filter = EnvFilter::try_from_default_env()
+        .or_else(|_| EnvFilter::try_new(level))
+        .context("Failed to build log filter")?;
+
+    let fmt_layer = tracing_subscriber::fmt::layer().with_filter(filter);
+
+    let console_layer = if tokio_console {
+        Some(console_subscriber::spawn())
+    } else {
+        None
+    };
+
+    tracing_subscriber::registry()
+        .with(console_layer)
+        .with(fmt_layer)
+        .init();
 
-    log4rs::init_config(config)?;
     Ok(())
 }
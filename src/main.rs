@@ -1,16 +1,23 @@
 use anyhow::Context;
 use clap::Parser;
-use log::info;
+use tracing::{info, warn};
 use prometheus_client::registry::Registry;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 mod collector;
+mod landlock;
 mod logging;
+mod metrics;
+mod otlp;
 mod server;
 
 use collector::BeesCollector;
+use landlock::init_landlock;
 use logging::init_logger;
+use metrics::ExporterMetrics;
+use otlp::spawn_otlp_export;
 use server::start_server;
 
 #[derive(Debug, Parser)]
@@ -31,30 +38,119 @@ struct Args {
     /// Logging level (error, warn, info, debug, trace)
     #[arg(short, long, default_value = "info")]
     pub log_level: String,
+
+    /// Interval in seconds between background refreshes of the metrics snapshot
+    #[arg(short, long, default_value = "15")]
+    pub refresh_interval: u64,
+
+    /// Enable the tokio-console subscriber endpoint for runtime inspection
+    #[arg(long)]
+    pub tokio_console: bool,
+
+    /// Extra static label applied to every metric, as `key=value`. May be
+    /// repeated. A `host` label is added automatically from the system hostname.
+    #[arg(long = "external-label", value_parser = parse_external_label)]
+    pub external_labels: Vec<(String, String)>,
+
+    /// OTLP collector endpoint to push metrics to. When unset, OTLP export is
+    /// disabled and only the `/metrics` scrape endpoint is served.
+    #[arg(long)]
+    pub otlp_endpoint: Option<String>,
+
+    /// Interval in seconds between OTLP pushes
+    #[arg(long, default_value = "30")]
+    pub otlp_interval: u64,
+
+    /// Disable the inotify watcher and refresh the snapshot on the fixed
+    /// `--refresh-interval` instead. This is an interval-based fallback, not a
+    /// return to per-scrape parsing (which the cached snapshot replaced). Use
+    /// it for filesystems that do not support file watching.
+    #[arg(long)]
+    pub no_watch: bool,
+}
+
+/// Parse an `external-label` argument of the form `key=value`.
+fn parse_external_label(raw: &str) -> Result<(String, String), String> {
+    match raw.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("expected `key=value`, got `{raw}`")),
+    }
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
-    init_logger(&args.log_level).context("Failed to initialize logger")?;
+    init_logger(&args.log_level, args.tokio_console).context("Failed to initialize logger")?;
 
     info!("Starting bees prometheus exporter");
     info!("Stats directory: {:?}", args.bees_work_dir);
     info!("Binding to {}:{}", args.address, args.port);
 
-    // Create the BeesCollector
-    let collector = BeesCollector::new(args.bees_work_dir)
+    // Apply the best-effort Landlock sandbox before touching any resources: it
+    // confines the process to reading the work dir and `/proc` and to binding
+    // the scrape (and optional tokio-console) port. A ruleset that is not fully
+    // enforced is logged inside init_landlock; an error it returns is logged
+    // here. Neither prevents startup on older kernels.
+    if let Err(e) = init_landlock(&args) {
+        warn!("Failed to apply Landlock sandbox: {}", e);
+    }
+
+    // Build the set of static labels: the resolved hostname plus any operator
+    // supplied `--external-label` pairs.
+    let mut labels = Vec::new();
+    match hostname::get() {
+        Ok(name) => labels.push(("host".to_string(), name.to_string_lossy().into_owned())),
+        Err(e) => info!("Could not resolve hostname for the `host` label: {}", e),
+    }
+    labels.extend(args.external_labels.clone());
+
+    // Register the exporter's own self-metrics under the `bees_exporter`
+    // sub-registry before the collector is created, so its initial collection
+    // already feeds the counters.
+    let mut registry = Registry::default();
+    let exporter_metrics = ExporterMetrics::register(&mut registry);
+
+    // Create the BeesCollector and start its background refresh task. The
+    // snapshot is seeded synchronously in new(), so the first scrape is served
+    // from real data rather than an empty map.
+    let collector = BeesCollector::new(args.bees_work_dir, labels, exporter_metrics.clone())
         .await
         .context("Failed to create BeesCollector")?;
+    // Keep the snapshot current either with the inotify watcher (default) or,
+    // where watching is unavailable, a fixed-interval refresh.
+    if args.no_watch {
+        collector.spawn_refresh(Duration::from_secs(args.refresh_interval));
+    } else {
+        collector
+            .spawn_watcher(Duration::from_millis(200))
+            .context("Failed to start file watcher")?;
+    }
 
     // Register the collector with the registry
-    let mut registry = Registry::default();
     registry.register_collector(Box::new(collector));
     let registry = Arc::new(Mutex::new(registry));
 
-    // Create and start the web server
-    start_server(registry, &args.address, args.port).await?;
+    // Optionally push the same registry to an OTLP collector alongside the
+    // scrape endpoint.
+    if let Some(endpoint) = args.otlp_endpoint.clone() {
+        spawn_otlp_export(
+            registry.clone(),
+            endpoint,
+            Duration::from_secs(args.otlp_interval),
+        )
+        .context("Failed to start OTLP export")?;
+    }
+
+    // Create and start the web server. The scrape counter is incremented by the
+    // `/metrics` handler so OTLP pushes are not counted as scrapes.
+    start_server(
+        registry,
+        exporter_metrics.scrapes.clone(),
+        &args.address,
+        args.port,
+    )
+    .await?;
 
     Ok(())
 }
@@ -0,0 +1,66 @@
+use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{Histogram, exponential_buckets};
+use prometheus_client::registry::Registry;
+
+/// Metrics describing the exporter's own health, registered under the
+/// `bees_exporter` sub-registry. These give operators insight into
+/// partially-failing collections without having to scrape logs.
+///
+/// The prometheus-client metric handles are internally reference counted, so
+/// cloning this struct shares the same underlying counters with the collector
+/// and its background tasks.
+#[derive(Debug, Clone)]
+pub struct ExporterMetrics {
+    /// Status files dropped because they could not be read or parsed.
+    pub parse_failures: Counter,
+    /// Prometheus scrapes served.
+    pub scrapes: Counter,
+    /// Wall-clock duration of a full `collect_all_data` pass, in seconds.
+    pub collect_duration: Histogram,
+    /// Number of bees filesystems currently discovered.
+    pub filesystems: Gauge,
+}
+
+impl ExporterMetrics {
+    /// Register the exporter's self-metrics under the `bees_exporter` prefix of
+    /// `registry`.
+    pub fn register(registry: &mut Registry) -> Self {
+        let sub = registry.sub_registry_with_prefix("bees_exporter");
+
+        let parse_failures = Counter::default();
+        sub.register(
+            "parse_failures",
+            "Number of status files dropped due to read or parse errors",
+            parse_failures.clone(),
+        );
+
+        let scrapes = Counter::default();
+        sub.register(
+            "scrapes",
+            "Number of Prometheus scrapes served",
+            scrapes.clone(),
+        );
+
+        let collect_duration = Histogram::new(exponential_buckets(0.001, 2.0, 12));
+        sub.register(
+            "collect_duration_seconds",
+            "Duration of a full status-file collection in seconds",
+            collect_duration.clone(),
+        );
+
+        let filesystems = Gauge::default();
+        sub.register(
+            "filesystems",
+            "Number of bees filesystems currently discovered",
+            filesystems.clone(),
+        );
+
+        Self {
+            parse_failures,
+            scrapes,
+            collect_duration,
+            filesystems,
+        }
+    }
+}
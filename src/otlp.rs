@@ -0,0 +1,151 @@
+use anyhow::{Context, Result};
+use opentelemetry::KeyValue;
+use opentelemetry::metrics::MeterProvider as _;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::metrics::{PeriodicReader, SdkMeterProvider};
+use prometheus_client::encoding::text::encode;
+use prometheus_client::registry::Registry;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time;
+use tracing::{debug, error, info};
+
+/// Spawn a background task that periodically pushes the current metric set to an
+/// OpenTelemetry collector. This is purely additive: the `/metrics` scrape
+/// endpoint keeps serving the same registry unchanged.
+///
+/// Each collection locks the shared [`Registry`], encodes it in the OpenMetrics
+/// text format, and re-emits every gauge sample as an OTLP gauge, carrying the
+/// sample's labels (notably the filesystem `uuid`) as attributes. This mirrors
+/// the way the libp2p metrics example bridges a `prometheus-client` registry to
+/// an OTLP collector.
+pub fn spawn_otlp_export(
+    registry: Arc<Mutex<Registry>>,
+    endpoint: String,
+    interval: Duration,
+) -> Result<()> {
+    let exporter = opentelemetry_otlp::MetricExporter::builder()
+        .with_tonic()
+        .with_endpoint(&endpoint)
+        .build()
+        .context("Failed to build OTLP metric exporter")?;
+
+    let reader = PeriodicReader::builder(exporter)
+        .with_interval(interval)
+        .build();
+    let provider = SdkMeterProvider::builder().with_reader(reader).build();
+    let meter = provider.meter("bees-prometheus-exporter");
+
+    info!(
+        "OTLP export enabled, pushing to {} every {:?}",
+        endpoint, interval
+    );
+
+    tokio::spawn(async move {
+        // Keep the provider alive for as long as the export task runs; dropping
+        // it would flush and shut down the pipeline.
+        let _provider = provider;
+        let mut gauges = HashMap::new();
+        let mut ticker = time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+
+            let mut buffer = String::new();
+            {
+                let registry = match registry.lock() {
+                    Ok(registry) => registry,
+                    Err(e) => {
+                        error!("Failed to lock registry for OTLP export: {}", e);
+                        continue;
+                    }
+                };
+                if let Err(e) = encode(&mut buffer, &registry) {
+                    error!("Failed to encode metrics for OTLP export: {}", e);
+                    continue;
+                }
+            }
+
+            for sample in parse_exposition(&buffer) {
+                let gauge = gauges
+                    .entry(sample.name.clone())
+                    .or_insert_with(|| meter.f64_gauge(sample.name.clone()).build());
+                gauge.record(sample.value, &sample.attributes);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// A single metric sample parsed out of the OpenMetrics text exposition.
+struct Sample {
+    name: String,
+    value: f64,
+    attributes: Vec<KeyValue>,
+}
+
+/// Parse the OpenMetrics text exposition into individual gauge samples. Comment
+/// and type lines (`# ...`) are skipped, as is the trailing `# EOF` marker.
+/// Samples of families not declared `gauge` by their `# TYPE` line (counters,
+/// histograms) are skipped too, so only gauge samples are returned.
+fn parse_exposition(buffer: &str) -> Vec<Sample> {
+    let mut samples = Vec::new();
+    let mut gauge_families = std::collections::HashSet::new();
+
+    for line in buffer.lines() {
+        if let Some(family) = line
+            .strip_prefix("# TYPE ")
+            .and_then(|rest| rest.strip_suffix(" gauge"))
+        {
+            gauge_families.insert(family.to_string());
+            continue;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((head, value)) = line.rsplit_once(' ') else {
+            continue;
+        };
+        let Ok(value) = value.parse::<f64>() else {
+            debug!("Skipping unparsable OTLP sample value in line: {}", line);
+            continue;
+        };
+
+        let (name, attributes) = match head.split_once('{') {
+            Some((name, labels)) => {
+                let labels = labels.trim_end_matches('}');
+                (name.to_string(), parse_labels(labels))
+            }
+            None => (head.to_string(), Vec::new()),
+        };
+
+        // Only gauge samples are re-emitted. Counter (`_total`) and histogram
+        // (`_bucket`/`_sum`/`_count`) series would otherwise be mis-typed as
+        // gauges, and the histogram buckets would carry a stray `le` attribute.
+        // A gauge's sample name equals its family name, so a name-suffix test
+        // is not used: it would also drop gauges such as `bees_thread_count`.
+        if !gauge_families.contains(&name) {
+            continue;
+        }
+
+        samples.push(Sample {
+            name,
+            value,
+            attributes,
+        });
+    }
+
+    samples
+}
+
+/// Parse a comma-separated `key="value"` label list into OTLP attributes.
+fn parse_labels(labels: &str) -> Vec<KeyValue> {
+    labels
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| KeyValue::new(key.to_string(), value.trim_matches('"').to_string()))
+        .collect()
+}
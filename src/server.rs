@@ -1,11 +1,15 @@
 use anyhow::Context;
 use axum::{Router, response::Html, routing::get};
-use log::{error, info};
+use tracing::{error, info};
+use prometheus_client::metrics::counter::Counter;
 use prometheus_client::registry::Registry;
 use std::sync::{Arc, Mutex};
 use tokio::net::TcpListener;
 
-async fn handler(registry: Arc<Mutex<Registry>>) -> String {
+async fn handler(registry: Arc<Mutex<Registry>>, scrapes: Counter) -> String {
+    // Count only real scrapes here; the OTLP task encodes the same registry on
+    // its own schedule and must not inflate this counter.
+    scrapes.inc();
     let mut buffer = String::new();
     {
         let registry = registry.lock().unwrap();
@@ -37,28 +41,61 @@ async fn root_handler() -> Html<&'static str> {
     )
 }
 
-fn init_app(registry: Arc<Mutex<Registry>>) -> Router {
+fn init_app(registry: Arc<Mutex<Registry>>, scrapes: Counter) -> Router {
     Router::new().route("/", get(root_handler)).route(
         "/metrics",
         get({
             let registry = registry.clone();
-            move || async move { handler(registry.clone()).await }
+            move || async move { handler(registry.clone(), scrapes.clone()).await }
         }),
     )
 }
 
 pub async fn start_server(
     registry: Arc<Mutex<Registry>>,
+    scrapes: Counter,
     address: &str,
     port: u16,
 ) -> anyhow::Result<()> {
-    let app = init_app(registry);
+    let app = init_app(registry, scrapes);
     let listener = TcpListener::bind((address, port))
         .await
         .with_context(|| format!("Failed to bind to {}:{}", address, port))?;
 
     info!("Listening on http://{}:{}", address, port);
-    axum::serve(listener, app).await.context("Server error")?;
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .context("Server error")?;
 
     Ok(())
 }
+
+/// Resolve once SIGINT or SIGTERM is received, letting in-flight scrapes finish
+/// before the server future completes. A handler that fails to install is
+/// logged and never triggers shutdown.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            error!("Failed to install SIGINT handler: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut stream) => {
+                stream.recv().await;
+            }
+            Err(e) => {
+                error!("Failed to install SIGTERM handler: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    tokio::select! {
+        _ = ctrl_c => info!("Received SIGINT, shutting down"),
+        _ = terminate => info!("Received SIGTERM, shutting down"),
+    }
+}
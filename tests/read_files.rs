@@ -1,36 +1,50 @@
-use log::{Level, Log, Metadata, Record};
 use std::sync::{Arc, LazyLock, Mutex};
-use uuid::Uuid;
+
+use prometheus_client::registry::Registry;
+use tracing::Level;
+use tracing::field::{Field, Visit};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
 
 use bees_prometheus_exporter::collector::BeesCollector;
+use bees_prometheus_exporter::metrics::ExporterMetrics;
 
-// Global logger for capturing all test log messages
+// The collector logs through `tracing`, so capture its events with a global
+// `tracing` subscriber rather than the `log` facade.
 static GLOBAL_MESSAGES: LazyLock<Arc<Mutex<Vec<(Level, String)>>>> = LazyLock::new(|| {
     let messages = Arc::new(Mutex::new(Vec::new()));
-    let logger = GlobalTestLogger {
+    let layer = CapturingLayer {
         messages: Arc::clone(&messages),
     };
-    let _ = log::set_boxed_logger(Box::new(logger));
-    log::set_max_level(log::LevelFilter::Debug);
+    // A failing init just means a subscriber is already installed; the captured
+    // buffer simply stays empty, which the assertion treats as "no warnings".
+    let _ = tracing_subscriber::registry().with(layer).try_init();
     messages
 });
 
-struct GlobalTestLogger {
+struct CapturingLayer {
     messages: Arc<Mutex<Vec<(Level, String)>>>,
 }
 
-impl Log for GlobalTestLogger {
-    fn enabled(&self, _metadata: &Metadata) -> bool {
-        true
-    }
-
-    fn log(&self, record: &Record) {
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for CapturingLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor(String::new());
+        event.record(&mut visitor);
         if let Ok(mut msgs) = self.messages.lock() {
-            msgs.push((record.level(), record.args().to_string()));
+            msgs.push((*event.metadata().level(), visitor.0));
         }
     }
+}
 
-    fn flush(&self) {}
+/// Extracts the `message` field of a `tracing` event into a plain string.
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{value:?}");
+        }
+    }
 }
 
 fn clear_and_get_messages() -> Arc<Mutex<Vec<(Level, String)>>> {
@@ -46,7 +60,7 @@ fn assert_no_warning_or_error_logs(messages: &Arc<Mutex<Vec<(Level, String)>>>)
     let log_messages = messages.lock().unwrap();
     let warning_or_error_messages: Vec<_> = log_messages
         .iter()
-        .filter(|(level, _)| matches!(level, Level::Warn | Level::Error))
+        .filter(|(level, _)| *level == Level::WARN || *level == Level::ERROR)
         .collect();
 
     assert!(
@@ -65,90 +79,62 @@ async fn test_collect_all_data_from_tests_directory() {
     // Get the tests directory relative to the project root at compile time
     let tests_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
 
-    // Call collect_all_data on the tests directory
-    let result = BeesCollector::collect_all_data(&tests_dir).await;
+    // Build the collector through the public API. `new` seeds the snapshot by
+    // running a full collection over the work dir before it returns. Files that
+    // fail to parse are logged and dropped rather than failing the call, which
+    // the UUID and log checks below catch.
+    let mut registry = Registry::default();
+    let metrics = ExporterMetrics::register(&mut registry);
+    let result = BeesCollector::new(tests_dir, Vec::new(), metrics.clone()).await;
 
     // Assert that the call was successful
     assert!(
         result.is_ok(),
-        "collect_all_data should succeed: {:?}",
+        "BeesCollector::new should succeed: {:?}",
         result
     );
 
-    let data = result.unwrap();
-
-    // We should have some data since there are .status files in the tests directory
+    // The initial collection records the number of discovered filesystems on
+    // the exporter's own gauge.
+    let discovered = metrics.filesystems.get();
     assert!(
-        !data.is_empty(),
-        "Should have collected data from status files"
+        discovered > 0,
+        "Should have discovered data from the status files, found {discovered}"
     );
 
-    // Check that we can parse the UUIDs from the filenames
-    let expected_uuids = vec![
+    // The parsed snapshot is private, so assert on behaviour through the one
+    // public view of it: the encoded exposition the collector produces.
+    registry.register_collector(Box::new(result.unwrap()));
+    let mut exposition = String::new();
+    prometheus_client::encoding::text::encode(&mut exposition, &registry)
+        .expect("encoding the registry should succeed");
+
+    // Every status file in the tests directory should appear as a `uuid` label.
+    let expected_uuids = [
         "0cadef6c-c480-41f2-95b7-511609815820",
         "464d43b3-8362-45b6-8f65-198ac3dcb507",
         "798ca972-f994-46ab-8e1a-9c3a24c92e85",
         "ab0f09d8-cbf5-461b-9068-31d9a69cb163",
     ];
-
-    for uuid_str in expected_uuids {
-        let uuid = Uuid::parse_str(uuid_str).expect("Should be valid UUID");
-        assert!(
-            data.contains_key(&uuid),
-            "Should contain data for UUID {}",
-            uuid
-        );
-
-        let fs_metrics = &data[&uuid];
-
-        // Check that we have some stats and progress data
-        assert!(
-            !fs_metrics.stats.is_empty(),
-            "Should have parsed stats data for UUID {}",
-            uuid
-        );
-        assert!(
-            !fs_metrics.progress.is_empty(),
-            "Should have parsed progress data for UUID {}",
-            uuid
-        );
-
-        // Check for at least one metric that should exist in most bees status files
-        // Use a more flexible approach since different files may have different metrics
-        let has_any_expected_metric = fs_metrics.stats.contains_key("crawl_done")
-            || fs_metrics.stats.contains_key("crawl_discard_high")
-            || fs_metrics.stats.contains_key("addr_block");
-
+    for uuid in expected_uuids {
         assert!(
-            has_any_expected_metric,
-            "Should contain at least one expected metric for UUID {}",
-            uuid
+            exposition.contains(uuid),
+            "Exposition should contain metrics for UUID {uuid}"
         );
     }
 
-    // Verify all data was parsed correctly
-    for (uuid, metrics) in &data {
-        assert!(
-            metrics.timestamp > 0,
-            "Should have a valid timestamp for UUID {}",
-            uuid
-        );
-
-        // Progress data should be structured correctly
-        for progress_row in &metrics.progress {
-            // Check that progress rows have sensible values
-            assert!(
-                !progress_row.extsz.is_empty(),
-                "Progress row should have extent size"
-            );
-            // datasz can be 0, gen_min/max should be valid numbers
-        }
-    }
-
-    println!(
-        "Successfully collected and validated data for {} UUIDs",
-        data.len()
+    // The TOTAL section should surface at least one well-known counter, and the
+    // PROGRESS section its datasz gauge.
+    assert!(
+        exposition.contains("bees_crawl_done") || exposition.contains("bees_addr_block"),
+        "Exposition should contain a known bees TOTAL counter"
     );
+    assert!(
+        exposition.contains("bees_progress_summary_datasz_bytes"),
+        "Exposition should contain the progress summary datasz gauge"
+    );
+
+    println!("Successfully collected and validated data for {discovered} UUIDs");
 
     // Check that no warning or error log messages were emitted
     // The collector is designed to succeed under any circumstance, dropping metrics that produced errors